@@ -1,8 +1,15 @@
 //! FFI interface for NSV parsing using nsv crate from crates.io
+#![cfg_attr(not(feature = "std-fs"), no_std)]
 
-use std::ffi::{CStr, CString};
-use std::os::raw::c_char;
-use std::ptr;
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::{c_char, CStr};
+use core::ptr;
 
 /// Opaque handle to parsed NSV data
 pub struct NsvData {
@@ -27,6 +34,39 @@ pub unsafe extern "C" fn nsv_parse(input: *const c_char) -> *mut NsvData {
     Box::into_raw(data)
 }
 
+/// Parse a binary-safe byte buffer of explicit length, reporting UTF-8 errors via `out_err`
+#[no_mangle]
+pub unsafe extern "C" fn nsv_parse_buffer(
+    ptr: *const u8,
+    len: usize,
+    out_err: *mut *mut c_char,
+) -> *mut NsvData {
+    if !out_err.is_null() {
+        *out_err = ptr::null_mut();
+    }
+
+    if ptr.is_null() {
+        return ptr::null_mut();
+    }
+
+    let bytes = core::slice::from_raw_parts(ptr, len);
+    let input_str = match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => {
+            if !out_err.is_null() {
+                if let Ok(err) = CString::new(format!("Invalid UTF-8 in input buffer: {}", e)) {
+                    *out_err = err.into_raw();
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    let rows = nsv::loads(input_str);
+    let data = Box::new(NsvData { rows });
+    Box::into_raw(data)
+}
+
 /// Get number of rows
 #[no_mangle]
 pub unsafe extern "C" fn nsv_row_count(data: *const NsvData) -> usize {
@@ -49,6 +89,42 @@ pub unsafe extern "C" fn nsv_col_count(data: *const NsvData, row: usize) -> usiz
     data_ref.rows[row].len()
 }
 
+/// Zero-copy borrowed view over a cell's UTF-8 bytes, with no NUL terminator
+#[repr(C)]
+pub struct NsvStringView {
+    pub buffer: *const u8,
+    pub len: usize,
+}
+
+/// Sentinel returned for out-of-bounds indices, distinguishing "missing" from "empty".
+pub const NULL_STRING: NsvStringView = NsvStringView {
+    buffer: ptr::null(),
+    len: 0,
+};
+
+/// Get cell value as a zero-copy view into the `NsvData` handle's own storage
+#[no_mangle]
+pub unsafe extern "C" fn nsv_get_cell_view(
+    data: *const NsvData,
+    row: usize,
+    col: usize,
+) -> NsvStringView {
+    if data.is_null() {
+        return NULL_STRING;
+    }
+
+    let data_ref = &*data;
+    if row >= data_ref.rows.len() || col >= data_ref.rows[row].len() {
+        return NULL_STRING;
+    }
+
+    let cell = &data_ref.rows[row][col];
+    NsvStringView {
+        buffer: cell.as_ptr(),
+        len: cell.len(),
+    }
+}
+
 /// Get cell value as C string
 #[no_mangle]
 pub unsafe extern "C" fn nsv_get_cell(
@@ -103,3 +179,133 @@ pub unsafe extern "C" fn nsv_encode(data: *const NsvData) -> *mut c_char {
         Err(_) => ptr::null_mut(),
     }
 }
+
+/// Column-oriented view over parsed data, for bulk ingestion by columnar consumers
+#[repr(C)]
+pub struct CNsvColumnar {
+    pub ncols: usize,
+    pub row_count: usize,
+    pub column_lengths: *mut usize,
+    pub columns: *mut *mut NsvStringView,
+}
+
+/// Build a columnar layout over `data`'s existing storage, padding ragged rows with `NULL_STRING`
+///
+/// The returned views borrow from `data` and are invalidated once it is freed.
+#[no_mangle]
+pub unsafe extern "C" fn nsv_to_columnar(data: *const NsvData) -> *mut CNsvColumnar {
+    if data.is_null() {
+        return ptr::null_mut();
+    }
+
+    let data_ref = &*data;
+    let row_count = data_ref.rows.len();
+    let ncols = data_ref.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut columns: Vec<*mut NsvStringView> = Vec::with_capacity(ncols);
+    let mut column_lengths: Vec<usize> = Vec::with_capacity(ncols);
+
+    for col in 0..ncols {
+        let mut views: Vec<NsvStringView> = Vec::with_capacity(row_count);
+        for row in &data_ref.rows {
+            match row.get(col) {
+                Some(cell) => views.push(NsvStringView {
+                    buffer: cell.as_ptr(),
+                    len: cell.len(),
+                }),
+                None => views.push(NULL_STRING),
+            }
+        }
+        column_lengths.push(row_count);
+        columns.push(views.as_mut_ptr());
+        core::mem::forget(views);
+    }
+
+    let result = Box::into_raw(Box::new(CNsvColumnar {
+        ncols,
+        row_count,
+        column_lengths: column_lengths.as_mut_ptr(),
+        columns: columns.as_mut_ptr(),
+    }));
+    core::mem::forget(column_lengths);
+    core::mem::forget(columns);
+    result
+}
+
+/// Free a `CNsvColumnar` built by `nsv_to_columnar`
+#[no_mangle]
+pub unsafe extern "C" fn nsv_free_columnar(columnar: *mut CNsvColumnar) {
+    if columnar.is_null() {
+        return;
+    }
+
+    let columnar = Box::from_raw(columnar);
+    if !columnar.columns.is_null() {
+        let columns = core::slice::from_raw_parts_mut(columnar.columns, columnar.ncols);
+        for &mut col_ptr in columns {
+            if !col_ptr.is_null() {
+                drop(Vec::from_raw_parts(
+                    col_ptr,
+                    columnar.row_count,
+                    columnar.row_count,
+                ));
+            }
+        }
+        drop(Vec::from_raw_parts(
+            columnar.columns,
+            columnar.ncols,
+            columnar.ncols,
+        ));
+    }
+    if !columnar.column_lengths.is_null() {
+        drop(Vec::from_raw_parts(
+            columnar.column_lengths,
+            columnar.ncols,
+            columnar.ncols,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe fn view_str(view: &NsvStringView) -> Option<&str> {
+        if view.buffer.is_null() {
+            None
+        } else {
+            Some(core::str::from_utf8(core::slice::from_raw_parts(view.buffer, view.len)).unwrap())
+        }
+    }
+
+    #[test]
+    fn columnar_round_trip_pads_ragged_rows_and_frees_cleanly() {
+        let data = Box::into_raw(Box::new(NsvData {
+            rows: vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string()],
+            ],
+        }));
+
+        unsafe {
+            let columnar = nsv_to_columnar(data);
+            assert!(!columnar.is_null());
+
+            let columnar_ref = &*columnar;
+            assert_eq!(columnar_ref.ncols, 2);
+            assert_eq!(columnar_ref.row_count, 2);
+
+            let columns = core::slice::from_raw_parts(columnar_ref.columns, columnar_ref.ncols);
+            let col0 = core::slice::from_raw_parts(columns[0], columnar_ref.row_count);
+            let col1 = core::slice::from_raw_parts(columns[1], columnar_ref.row_count);
+
+            assert_eq!(view_str(&col0[0]), Some("a"));
+            assert_eq!(view_str(&col0[1]), Some("c"));
+            assert_eq!(view_str(&col1[0]), Some("b"));
+            assert_eq!(view_str(&col1[1]), None);
+
+            nsv_free_columnar(columnar);
+            nsv_free(data);
+        }
+    }
+}
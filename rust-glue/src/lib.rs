@@ -1,7 +1,9 @@
 use std::ffi::{CStr, CString};
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_void};
 use std::ptr;
 
+pub use nsv_ffi::NsvStringView;
+
 #[repr(C)]
 pub struct CNsvResult {
     pub rows: *mut *mut *mut c_char,
@@ -10,6 +12,9 @@ pub struct CNsvResult {
     pub error: *mut c_char,
 }
 
+/// Parse a file from disk. Requires the `std-fs` feature (enabled by default);
+/// hosts without a filesystem should go through `nsv_parse_buffer` instead.
+#[cfg(feature = "std-fs")]
 #[no_mangle]
 pub extern "C" fn nsv_parse_file(filename: *const c_char) -> *mut CNsvResult {
     let filename_str = unsafe {
@@ -43,16 +48,38 @@ pub extern "C" fn nsv_parse_file(filename: *const c_char) -> *mut CNsvResult {
     let data = nsv::loads(&content);
     let nrows = data.len();
 
-    let mut rows_vec: Vec<*mut *mut c_char> = Vec::with_capacity(nrows);
-    let mut ncols_vec: Vec<usize> = Vec::with_capacity(nrows);
-
+    // Build into safe, owned storage first so an interior-NUL error below
+    // drops everything converted so far instead of leaking forgotten Vecs.
+    let mut rows: Vec<Vec<CString>> = Vec::with_capacity(nrows);
     for row in data {
-        let ncols = row.len();
-        ncols_vec.push(ncols);
-        let mut row_vec: Vec<*mut c_char> = Vec::with_capacity(ncols);
+        let mut row_strings: Vec<CString> = Vec::with_capacity(row.len());
         for cell in row {
-            row_vec.push(CString::new(cell).unwrap().into_raw());
+            match CString::new(cell) {
+                Ok(c_string) => row_strings.push(c_string),
+                Err(_) => {
+                    let err = CString::new(
+                        "Error: cell contains an interior NUL byte; use nsv_parse_buffer instead",
+                    )
+                    .unwrap();
+                    return Box::into_raw(Box::new(CNsvResult {
+                        rows: ptr::null_mut(),
+                        nrows: 0,
+                        ncols: ptr::null_mut(),
+                        error: err.into_raw(),
+                    }));
+                }
+            }
         }
+        rows.push(row_strings);
+    }
+
+    let mut rows_vec: Vec<*mut *mut c_char> = Vec::with_capacity(nrows);
+    let mut ncols_vec: Vec<usize> = Vec::with_capacity(nrows);
+
+    for row_strings in rows {
+        ncols_vec.push(row_strings.len());
+        let mut row_vec: Vec<*mut c_char> =
+            row_strings.into_iter().map(|s| s.into_raw()).collect();
         rows_vec.push(row_vec.as_mut_ptr());
         std::mem::forget(row_vec);
     }
@@ -68,6 +95,113 @@ pub extern "C" fn nsv_parse_file(filename: *const c_char) -> *mut CNsvResult {
     result
 }
 
+/// Invoked once per parsed record with a borrowed view array valid only for the call's duration
+pub type NsvRowCallback =
+    extern "C" fn(ctx: *mut c_void, row: *const NsvStringView, ncols: usize) -> bool;
+
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+fn emit_streaming_records(text: &str, cb: NsvRowCallback, ctx: *mut c_void) -> bool {
+    for row in nsv::loads(text) {
+        let views: Vec<NsvStringView> = row
+            .iter()
+            .map(|cell| NsvStringView {
+                buffer: cell.as_ptr(),
+                len: cell.len(),
+            })
+            .collect();
+        if !cb(ctx, views.as_ptr(), views.len()) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Read `reader` in `chunk_size` chunks, reassembling lines split across
+/// chunk boundaries via a byte-level carry buffer, and invoke `on_line` once
+/// per complete (or final, unterminated) line. Splitting on raw `\n` bytes
+/// is safe even mid multi-byte character, since `\n` never appears as a UTF-8
+/// continuation byte. Stops early if `on_line` returns `false`.
+fn read_lines<R: std::io::Read>(
+    mut reader: R,
+    chunk_size: usize,
+    mut on_line: impl FnMut(&str) -> bool,
+) -> Result<(), String> {
+    let mut carry: Vec<u8> = Vec::new();
+    let mut buf = vec![0u8; chunk_size];
+
+    loop {
+        let n = reader
+            .read(&mut buf)
+            .map_err(|e| format!("Error reading file: {}", e))?;
+        if n == 0 {
+            break;
+        }
+        carry.extend_from_slice(&buf[..n]);
+
+        while let Some(pos) = carry.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = carry.drain(..=pos).collect();
+            let line_str =
+                std::str::from_utf8(&line).map_err(|e| format!("Invalid UTF-8 in file: {}", e))?;
+            if !on_line(line_str) {
+                return Ok(());
+            }
+        }
+    }
+
+    if !carry.is_empty() {
+        let line_str =
+            std::str::from_utf8(&carry).map_err(|e| format!("Invalid UTF-8 in file: {}", e))?;
+        on_line(line_str);
+    }
+
+    Ok(())
+}
+
+/// Stream-parse a file in bounded chunks, invoking `cb` once per record instead of buffering it all
+#[cfg(feature = "std-fs")]
+#[no_mangle]
+pub extern "C" fn nsv_parse_file_streaming(
+    filename: *const c_char,
+    cb: NsvRowCallback,
+    ctx: *mut c_void,
+) -> *mut c_char {
+    let filename_str = unsafe {
+        match CStr::from_ptr(filename).to_str() {
+            Ok(s) => s,
+            Err(e) => return CString::new(format!("Invalid UTF-8: {}", e)).unwrap().into_raw(),
+        }
+    };
+
+    let file = match std::fs::File::open(filename_str) {
+        Ok(f) => f,
+        Err(e) => {
+            return CString::new(format!("Error opening file: {}", e))
+                .unwrap()
+                .into_raw()
+        }
+    };
+
+    // Cells are one per line; an empty line terminates the current record.
+    let mut record = String::new();
+    let mut keep_going = true;
+    let outcome = read_lines(file, STREAM_CHUNK_SIZE, |line| {
+        record.push_str(line);
+        if line == "\n" {
+            keep_going = emit_streaming_records(&record, cb, ctx);
+            record.clear();
+        }
+        keep_going
+    });
+    if let Err(e) = outcome {
+        return CString::new(e).unwrap().into_raw();
+    }
+    if keep_going && !record.is_empty() {
+        emit_streaming_records(&record, cb, ctx);
+    }
+    ptr::null_mut()
+}
+
 #[no_mangle]
 pub extern "C" fn nsv_free_result(result: *mut CNsvResult) {
     if result.is_null() {
@@ -100,3 +234,48 @@ pub extern "C" fn nsv_free_result(result: *mut CNsvResult) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_lines_reassembles_lines_split_across_chunk_boundaries() {
+        let input = "abc\nd\u{e9}f\nghi\n";
+        let mut lines = Vec::new();
+        read_lines(Cursor::new(input.as_bytes()), 3, |line| {
+            lines.push(line.to_string());
+            true
+        })
+        .unwrap();
+
+        assert_eq!(lines, vec!["abc\n", "d\u{e9}f\n", "ghi\n"]);
+    }
+
+    #[test]
+    fn read_lines_flushes_final_unterminated_line_at_eof() {
+        let input = "abc\nxy";
+        let mut lines = Vec::new();
+        read_lines(Cursor::new(input.as_bytes()), 2, |line| {
+            lines.push(line.to_string());
+            true
+        })
+        .unwrap();
+
+        assert_eq!(lines, vec!["abc\n", "xy"]);
+    }
+
+    #[test]
+    fn read_lines_stops_early_when_callback_returns_false() {
+        let input = "one\ntwo\nthree\n";
+        let mut lines = Vec::new();
+        read_lines(Cursor::new(input.as_bytes()), 4, |line| {
+            lines.push(line.to_string());
+            lines.len() < 2
+        })
+        .unwrap();
+
+        assert_eq!(lines, vec!["one\n", "two\n"]);
+    }
+}